@@ -1,20 +1,185 @@
 use ink::{
     env::Error as InkEnvError,
     prelude::{format, string::String},
+    primitives::AccountId,
     LangError,
 };
-use openbrush::contracts::psp22::PSP22Error;
+use openbrush::contracts::{psp22::PSP22Error, psp34::PSP34Error};
 
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum AzTradingCompetitionError {
+    AccountAlreadyPlaced,
+    AdminAlreadyJudge,
+    AdminFeeAlreadyCollected,
+    AllCompetitorsNotPlaced,
+    AllCompetitorsNotProcessed,
+    AllCompetitorsPlaced,
+    AllowedTokensMissingEntryFeeToken,
+    AllowedTokensNotPermitted,
+    AlreadyJudged,
+    AlreadyVoted,
+    AmountNotCoveredByPrizePool,
+    AmountNotCoveredByStreakBonusPool,
+    AmountNotCoveredByTreasuryFund,
+    AmountNotPositive,
+    AmountNotRecoverable,
+    AmountZero,
+    AzeroProcessingFeeMissing,
+    AzeroProcessingFeesMissing,
+    BatchTooLarge,
+    BlackoutTooLong,
+    ChallengeOpen,
+    ChallengeWindowNotPassed,
+    ChallengeWindowPassed,
+    ClaimDeadlineNotPassed,
+    ClaimPeriodTooShort,
+    CodeHashTimelockNotPassed,
+    CodeHashTimelockTooShort,
+    CompetitionAlreadyInSeason,
+    CompetitionCancelled,
+    CompetitionCanStillBeJudged,
+    CompetitionEnded,
+    CompetitionFull,
+    CompetitionInvalid,
+    CompetitionNotCancelled,
+    CompetitionNotEnded,
+    CompetitionNotInProgress,
+    CompetitionNotInSeason,
+    CompetitionNotStarted,
+    CompetitionsLimitReached,
+    CompetitionStarted,
+    CompetitionTerminatingEarly,
+    CompetitorAlreadyDisqualified,
+    CompetitorAlreadyPlaced,
+    CompetitorAlreadyProcessed,
+    CompetitorDisqualified,
+    CompetitorsLimitNotPositive,
     ContractCall(LangError),
+    DepositsAlreadyReclaimed,
+    DeregistrationClosed,
+    DescriptionUriTooLong,
+    DiaPriceDetailsUnavailable,
+    DiaPriceSymbolAlreadyValid,
+    DiaPriceSymbolInUse,
+    DurationTooShort,
+    EarlyTerminationNotRequested,
+    EarlyTerminationTimelockNotPassed,
+    EntryFeeAmountNotPositive,
+    EntryFeeTokenNotPermitted,
+    FeePercentageNumeratorTooHigh,
+    FinalValuesUpdateStarted,
+    IdentityNotVerified,
     InkEnvError(String),
+    InsertionHintsMismatch,
+    InsufficientAzeroBalance,
+    InsufficientCompetitionBalance {
+        token: AccountId,
+        available: u128,
+        requested: u128,
+    },
+    InsufficientInsuranceFund,
+    InvalidDiaPriceSymbol,
+    InvalidPairTokenCombinations,
+    InviteCodeIncorrect,
+    JudgeBondMissing,
+    JudgeCommitteeThresholdInvalid,
+    JudgeCommitteeTooLarge,
+    JudgeDeadlineDurationTooShort,
+    JudgeDeadlineNotPassed,
+    JudgeEscalationPeriodNotPassed,
+    JudgePlaceAttemptLimitReached,
+    JudgeResetLimitReached,
+    JudgesNotUnique,
+    LimitNotPositive,
+    MathError(MathError),
+    MaximumBatchSizeNotPositive,
+    MaximumPathLengthTooShort,
+    MembershipTokenRequired,
+    MetadataUriTooLong,
+    MinimumCompetitorRequirementsMet,
+    MinimumCompetitorRequirementsNotMet,
+    MinimumSwapsNotMade,
+    NameTooLong,
+    NativeTransferFailed,
+    NextJudgeAbsent,
+    NextJudgeNotOutperformed,
+    NextJudgePresent,
+    NoPrizeToCollect,
     NotFound(String),
+    NotOnAllowlist,
+    NumeratorGreaterThanDenominator,
+    NumeratorNotPositive,
+    NumeratorOutOfRange,
+    OracleFailurePeriodNotPassed,
+    OraclesCountInvalid,
+    PairAlreadyAllowed,
+    PairNotAllowed,
+    ParticipationBonusAlreadyReleased,
+    ParticipationBonusExists,
+    ParticipationBonusInvalid,
+    PathContainsCycle,
+    PathEmpty,
+    PathInvalid,
+    PathTooLong,
+    Paused,
+    PayoutStructureNotSet,
+    PeriodNotPositive,
+    PlacementAlreadyConfirmed,
+    PlacementCheckpointMismatch,
+    PlacementNotConfirmed,
+    PointsPerPlaceEmpty,
+    PointsPerPlaceTooLong,
+    PositionInvalid,
+    PriceChallengeBondMissing,
+    PriceCorrectionNotRequested,
+    PriceCorrectionTimelockNotPassed,
+    PrizeAlreadyCollected,
+    ProcessingFeeTopUpNotRefundable,
     PSP22Error(PSP22Error),
+    PSP34Error(PSP34Error),
+    RegistrantsPresent,
+    RegistrationEndAfterStart,
+    RegistrationEnded,
+    RegistrationOpen,
+    RouterAlreadyAllowed,
     RouterError(RouterError),
+    SeasonCompetitionsLimitReached,
+    SeasonPlacementStarted,
+    SeasonPointsAlreadyAwarded,
+    SeasonPointsNotAwarded,
+    SponsorsLimitReached,
+    StartNotInFuture,
+    StorageUpToDate,
+    StreakAlreadyUpdated,
+    SwapLimitReached,
+    SwapPriceDeviation,
+    SwapsBlackedOut,
+    SwapsEmpty,
+    TicketOwnerUnavailable,
+    TokenAlreadyCollected,
+    TokenAlreadyRegistered,
+    TokenDecimalsMissing,
+    TokenDiaPriceSymbolsIncomplete,
+    TokenInAllowedPairs,
+    TokenNotPermitted,
+    TokenNotRegistered,
+    TokenPricesAlreadySet,
+    TokenPricesForceSetTimelockNotPassed,
+    TokenPricesIncomplete,
+    TokenPricesNotSet,
+    TokenPricesProposalMismatch,
+    TokenStartPricesAlreadySet,
+    TokenTracked,
+    TopUpAmountNotPositive,
     Unauthorised,
-    UnprocessableEntity(String),
+    WazeroNotSet,
+    YieldDepositAbsent,
+    YieldDepositPresent,
+    YieldNotWithdrawn,
+    YieldSourceNotSet,
+    YieldSourceNotWhitelisted,
+    ZeroCompetitorsPlaced,
 }
 impl From<InkEnvError> for AzTradingCompetitionError {
     fn from(e: InkEnvError) -> Self {
@@ -26,11 +191,21 @@ impl From<LangError> for AzTradingCompetitionError {
         AzTradingCompetitionError::ContractCall(e)
     }
 }
+impl From<MathError> for AzTradingCompetitionError {
+    fn from(e: MathError) -> Self {
+        AzTradingCompetitionError::MathError(e)
+    }
+}
 impl From<PSP22Error> for AzTradingCompetitionError {
     fn from(e: PSP22Error) -> Self {
         AzTradingCompetitionError::PSP22Error(e)
     }
 }
+impl From<PSP34Error> for AzTradingCompetitionError {
+    fn from(e: PSP34Error) -> Self {
+        AzTradingCompetitionError::PSP34Error(e)
+    }
+}
 impl From<RouterError> for AzTradingCompetitionError {
     fn from(e: RouterError) -> Self {
         AzTradingCompetitionError::RouterError(e)